@@ -4,6 +4,19 @@
 mod access_registry {
     use ink::storage::Mapping;
 
+    /// Identifier for an access-control role (32-byte hash).
+    pub type RoleId = [u8; 32];
+
+    /// Root admin role. Members of this role administer every role whose admin
+    /// has not been explicitly set to something else.
+    pub const DEFAULT_ADMIN_ROLE: RoleId = [0u8; 32];
+
+    /// Role required to grant or revoke entitlements.
+    pub const ENTITLEMENT_ADMIN: RoleId = *b"arkavo.role.entitlement.admin\0\0\0";
+
+    /// Role required to create or revoke sessions.
+    pub const SESSION_ISSUER: RoleId = *b"arkavo.role.session.issuer\0\0\0\0\0\0";
+
     /// Defines entitlement levels for access control
     #[derive(Default, Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
@@ -23,6 +36,8 @@ mod access_registry {
     #[derive(Default, Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct SessionGrant {
+        /// Account the session was issued to.
+        pub holder: Address,
         /// Ephemeral public key (33 bytes compressed EC point).
         /// The agent signs requests with the corresponding private key.
         pub eph_pub_key: ink::prelude::vec::Vec<u8>,
@@ -37,15 +52,59 @@ mod access_registry {
         pub created_at_block: u64,
     }
 
+    /// An entitlement grant with an optional block-height deadline.
+    ///
+    /// `expires_at_block` is [`u64::MAX`] for a perpetual grant; once the
+    /// current block passes it the grant is treated as
+    /// [`EntitlementLevel::None`].
+    #[derive(Default, Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct EntitlementRecord {
+        /// Entitlement level held by the account.
+        pub level: EntitlementLevel,
+        /// Block number at which the entitlement lapses.
+        pub expires_at_block: u64,
+    }
+
+    /// An on-chain document record consumed by an off-chain key server.
+    ///
+    /// Stores the entitlement a caller must hold and the common-encrypted
+    /// document key that the key server needs to assemble a decryption share.
+    #[derive(Default, Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct DocumentEntry {
+        /// Minimum entitlement level required to access the document.
+        pub required_level: EntitlementLevel,
+        /// Common-encrypted document key.
+        pub encrypted_key: ink::prelude::vec::Vec<u8>,
+    }
+
     /// Access registry contract for managing entitlements
     #[ink(storage)]
     pub struct AccessRegistry {
-        /// Mapping from account to their entitlement level
-        entitlements: Mapping<Address, EntitlementLevel>,
+        /// Mapping from account to their entitlement record
+        entitlements: Mapping<Address, EntitlementRecord>,
         /// Mapping from session ID to session grant
         sessions: Mapping<[u8; 32], SessionGrant>,
+        /// Index from `(holder, scope_id)` to the latest session issued for it,
+        /// letting `can_access` find an account's session without iterating.
+        account_sessions: Mapping<(Address, [u8; 32]), [u8; 32]>,
+        /// Registered documents keyed by scope identifier.
+        documents: Mapping<[u8; 32], DocumentEntry>,
         /// Contract owner who can grant/revoke entitlements
         owner: Address,
+        /// Owner proposed via [`AccessRegistry::propose_owner`], awaiting
+        /// acceptance. `None` when no transfer is in flight.
+        pending_owner: Option<Address>,
+        /// Set of `(role, account)` pairs recording role membership.
+        roles: Mapping<(RoleId, Address), ()>,
+        /// Admin role for each role. A role whose admin is unset is
+        /// administered by [`DEFAULT_ADMIN_ROLE`].
+        role_admin: Mapping<RoleId, RoleId>,
+        /// Whether the contract is paused. While paused, state-mutating
+        /// messages are halted as an emergency stop; `renounce_role` and
+        /// `unpause` stay available.
+        paused: bool,
     }
 
     /// Events emitted by the contract
@@ -62,6 +121,12 @@ mod access_registry {
         account: Address,
     }
 
+    #[ink(event)]
+    pub struct EntitlementExpired {
+        #[ink(topic)]
+        account: Address,
+    }
+
     #[ink(event)]
     pub struct SessionCreated {
         #[ink(topic)]
@@ -75,6 +140,82 @@ mod access_registry {
         session_id: [u8; 32],
     }
 
+    #[ink(event)]
+    pub struct DocumentRegistered {
+        #[ink(topic)]
+        scope_id: [u8; 32],
+        required_level: EntitlementLevel,
+    }
+
+    #[ink(event)]
+    pub struct DocumentRetired {
+        #[ink(topic)]
+        scope_id: [u8; 32],
+    }
+
+    #[ink(event)]
+    pub struct AccessChecked {
+        #[ink(topic)]
+        account: Address,
+        #[ink(topic)]
+        scope_id: [u8; 32],
+        granted: bool,
+    }
+
+    #[ink(event)]
+    pub struct SessionExpired {
+        #[ink(topic)]
+        session_id: [u8; 32],
+    }
+
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        role: RoleId,
+        #[ink(topic)]
+        account: Address,
+        #[ink(topic)]
+        by: Address,
+    }
+
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        role: RoleId,
+        #[ink(topic)]
+        account: Address,
+        #[ink(topic)]
+        by: Address,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferProposed {
+        #[ink(topic)]
+        current_owner: Address,
+        #[ink(topic)]
+        pending_owner: Address,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: Address,
+        #[ink(topic)]
+        new_owner: Address,
+    }
+
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        by: Address,
+    }
+
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        by: Address,
+    }
+
     /// Errors that can occur during contract execution
     #[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -85,6 +226,14 @@ mod access_registry {
         EntitlementNotFound,
         /// Session not found
         SessionNotFound,
+        /// The contract is paused
+        ContractPaused,
+        /// Caller does not hold the role required for this operation
+        MissingRole,
+        /// Caller is not the pending owner
+        NotPendingOwner,
+        /// ECDSA recovery failed for the supplied signature
+        SignatureInvalid,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -99,10 +248,23 @@ mod access_registry {
         /// Constructor that initializes the contract
         #[ink(constructor)]
         pub fn new() -> Self {
+            let caller = Self::env().caller();
+            let mut roles = Mapping::default();
+            // Bootstrap the deployer as root admin and grant it the two
+            // operational roles so the contract is usable out of the box.
+            roles.insert((DEFAULT_ADMIN_ROLE, caller), &());
+            roles.insert((ENTITLEMENT_ADMIN, caller), &());
+            roles.insert((SESSION_ISSUER, caller), &());
             Self {
                 entitlements: Mapping::default(),
                 sessions: Mapping::default(),
-                owner: Self::env().caller(),
+                account_sessions: Mapping::default(),
+                documents: Mapping::default(),
+                owner: caller,
+                pending_owner: None,
+                roles,
+                role_admin: Mapping::default(),
+                paused: false,
             }
         }
 
@@ -112,12 +274,16 @@ mod access_registry {
             &mut self,
             account: Address,
             level: EntitlementLevel,
+            expires_at_block: Option<u64>,
         ) -> Result<()> {
-            if self.env().caller() != self.owner {
-                return Err(Error::NotOwner);
-            }
+            self.ensure_role(ENTITLEMENT_ADMIN)?;
+            self.ensure_not_paused()?;
 
-            self.entitlements.insert(account, &level);
+            let record = EntitlementRecord {
+                level,
+                expires_at_block: expires_at_block.unwrap_or(u64::MAX),
+            };
+            self.entitlements.insert(account, &record);
 
             self.env().emit_event(EntitlementGranted {
                 account,
@@ -130,9 +296,8 @@ mod access_registry {
         /// Revoke an entitlement from an account
         #[ink(message)]
         pub fn revoke_entitlement(&mut self, account: Address) -> Result<()> {
-            if self.env().caller() != self.owner {
-                return Err(Error::NotOwner);
-            }
+            self.ensure_role(ENTITLEMENT_ADMIN)?;
+            self.ensure_not_paused()?;
 
             self.entitlements.remove(account);
 
@@ -141,10 +306,43 @@ mod access_registry {
             Ok(())
         }
 
+        /// Remove every supplied account whose entitlement has lapsed.
+        ///
+        /// Emits an [`EntitlementExpired`] event per removal and returns the
+        /// number of entitlements swept. Accounts that are unknown or still
+        /// live are skipped.
+        #[ink(message)]
+        pub fn sweep_expired_entitlements(
+            &mut self,
+            accounts: ink::prelude::vec::Vec<Address>,
+        ) -> Result<u32> {
+            self.ensure_role(ENTITLEMENT_ADMIN)?;
+            self.ensure_not_paused()?;
+
+            let current_block = self.env().block_number() as u64;
+            let mut swept = 0u32;
+            for account in accounts {
+                if let Some(record) = self.entitlements.get(account) {
+                    if current_block > record.expires_at_block {
+                        self.entitlements.remove(account);
+                        self.env().emit_event(EntitlementExpired { account });
+                        swept += 1;
+                    }
+                }
+            }
+
+            Ok(swept)
+        }
+
         /// Check the entitlement level of an account
         #[ink(message)]
         pub fn get_entitlement(&self, account: Address) -> EntitlementLevel {
-            self.entitlements.get(account).unwrap_or_default()
+            match self.entitlements.get(account) {
+                Some(record) if self.env().block_number() as u64 <= record.expires_at_block => {
+                    record.level
+                }
+                _ => EntitlementLevel::None,
+            }
         }
 
         /// Check if an account has at least a specific entitlement level
@@ -164,6 +362,223 @@ mod access_registry {
             self.owner
         }
 
+        /// Get the pending owner, if an ownership transfer is in flight.
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<Address> {
+            self.pending_owner
+        }
+
+        /// Propose a new owner, starting a two-step handoff.
+        ///
+        /// Only the current owner can propose. The transfer completes when
+        /// `new_owner` calls [`AccessRegistry::accept_ownership`].
+        #[ink(message)]
+        pub fn propose_owner(&mut self, new_owner: Address) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_not_paused()?;
+
+            self.pending_owner = Some(new_owner);
+
+            self.env().emit_event(OwnershipTransferProposed {
+                current_owner: self.owner,
+                pending_owner: new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Accept a pending ownership transfer.
+        ///
+        /// Only the pending owner can accept; on success it becomes the owner
+        /// and the pending slot is cleared.
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if self.pending_owner != Some(caller) {
+                return Err(Error::NotPendingOwner);
+            }
+            self.ensure_not_paused()?;
+
+            let previous_owner = self.owner;
+            self.owner = caller;
+            self.pending_owner = None;
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Renounce ownership, leaving the contract without an owner.
+        ///
+        /// Only the current owner can renounce. Any in-flight proposal is
+        /// cleared. This is irreversible.
+        #[ink(message)]
+        pub fn renounce_ownership(&mut self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            // Reject while paused: renouncing clears the owner, which would
+            // leave nobody able to `unpause()` and freeze the contract forever.
+            self.ensure_not_paused()?;
+
+            let previous_owner = self.owner;
+            self.owner = Address::default();
+            self.pending_owner = None;
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: Address::default(),
+            });
+
+            Ok(())
+        }
+
+        /// Check whether `account` holds `role`.
+        #[ink(message)]
+        pub fn has_role(&self, role: RoleId, account: Address) -> bool {
+            self.roles.contains((role, account))
+        }
+
+        /// Get the admin role that controls membership of `role`.
+        #[ink(message)]
+        pub fn get_role_admin(&self, role: RoleId) -> RoleId {
+            self.role_admin.get(role).unwrap_or(DEFAULT_ADMIN_ROLE)
+        }
+
+        /// Grant `role` to `account`.
+        ///
+        /// The caller must hold the admin role of `role`.
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleId, account: Address) -> Result<()> {
+            self.ensure_role(self.get_role_admin(role))?;
+            self.ensure_not_paused()?;
+            self.do_grant_role(role, account);
+            Ok(())
+        }
+
+        /// Revoke `role` from `account`.
+        ///
+        /// The caller must hold the admin role of `role`.
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: RoleId, account: Address) -> Result<()> {
+            self.ensure_role(self.get_role_admin(role))?;
+            self.ensure_not_paused()?;
+            self.do_revoke_role(role, account);
+            Ok(())
+        }
+
+        /// Renounce `role` for the caller.
+        ///
+        /// An account may always drop one of its own roles.
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: RoleId) -> Result<()> {
+            let caller = self.env().caller();
+            self.do_revoke_role(role, caller);
+            Ok(())
+        }
+
+        /// Set the admin role controlling membership of `role`.
+        ///
+        /// Only a member of [`DEFAULT_ADMIN_ROLE`] can rewire the hierarchy.
+        #[ink(message)]
+        pub fn set_role_admin(&mut self, role: RoleId, admin_role: RoleId) -> Result<()> {
+            self.ensure_role(DEFAULT_ADMIN_ROLE)?;
+            self.ensure_not_paused()?;
+            self.role_admin.insert(role, &admin_role);
+            Ok(())
+        }
+
+        /// Whether the contract is currently paused.
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// Pause the contract, halting state-mutating messages other than
+        /// `renounce_role` and `unpause`.
+        ///
+        /// Only the contract owner can pause.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            self.paused = true;
+
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Resume the contract after a pause.
+        ///
+        /// Only the contract owner can unpause.
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+
+            self.paused = false;
+
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Ensure the contract is not paused, returning [`Error::ContractPaused`]
+        /// otherwise.
+        fn ensure_not_paused(&self) -> Result<()> {
+            if self.paused {
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
+
+        /// Ensure the caller holds `role`, returning [`Error::MissingRole`]
+        /// otherwise.
+        fn ensure_role(&self, role: RoleId) -> Result<()> {
+            if !self.has_role(role, self.env().caller()) {
+                return Err(Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        /// Insert a role membership and emit [`RoleGranted`] if it was not
+        /// already present.
+        fn do_grant_role(&mut self, role: RoleId, account: Address) {
+            if !self.roles.contains((role, account)) {
+                self.roles.insert((role, account), &());
+                self.env().emit_event(RoleGranted {
+                    role,
+                    account,
+                    by: self.env().caller(),
+                });
+            }
+        }
+
+        /// Remove a role membership and emit [`RoleRevoked`] if it was present.
+        fn do_revoke_role(&mut self, role: RoleId, account: Address) {
+            if self.roles.contains((role, account)) {
+                self.roles.remove((role, account));
+                self.env().emit_event(RoleRevoked {
+                    role,
+                    account,
+                    by: self.env().caller(),
+                });
+            }
+        }
+
         /// Helper function to convert entitlement level to numeric value for comparison
         fn level_value(level: EntitlementLevel) -> u8 {
             match level {
@@ -181,15 +596,16 @@ mod access_registry {
         pub fn create_session(
             &mut self,
             session_id: [u8; 32],
+            holder: Address,
             eph_pub_key: ink::prelude::vec::Vec<u8>,
             scope_id: [u8; 32],
             expires_at_block: u64,
         ) -> Result<()> {
-            if self.env().caller() != self.owner {
-                return Err(Error::NotOwner);
-            }
+            self.ensure_role(SESSION_ISSUER)?;
+            self.ensure_not_paused()?;
 
             let grant = SessionGrant {
+                holder,
                 eph_pub_key,
                 scope_id,
                 expires_at_block,
@@ -198,6 +614,7 @@ mod access_registry {
             };
 
             self.sessions.insert(session_id, &grant);
+            self.account_sessions.insert((holder, scope_id), &session_id);
 
             self.env().emit_event(SessionCreated {
                 session_id,
@@ -213,18 +630,188 @@ mod access_registry {
             self.sessions.get(session_id)
         }
 
-        /// Revoke a session grant.
+        /// Check whether a session is currently usable.
         ///
-        /// Only the contract owner can revoke sessions.
+        /// Returns true only when the grant exists, has not been revoked, and
+        /// has not passed its `expires_at_block`.
         #[ink(message)]
-        pub fn revoke_session(&mut self, session_id: [u8; 32]) -> Result<()> {
+        pub fn is_session_active(&self, session_id: [u8; 32]) -> bool {
+            match self.sessions.get(session_id) {
+                Some(grant) => {
+                    !grant.is_revoked
+                        && self.env().block_number() as u64 <= grant.expires_at_block
+                }
+                None => false,
+            }
+        }
+
+        /// Remove every supplied session whose `expires_at_block` has passed,
+        /// reclaiming storage.
+        ///
+        /// Emits a [`SessionExpired`] event per removal and returns the number
+        /// of sessions pruned. Unknown or still-live ids are skipped.
+        #[ink(message)]
+        pub fn prune_expired_sessions(&mut self, ids: ink::prelude::vec::Vec<[u8; 32]>) -> Result<u32> {
+            self.ensure_role(SESSION_ISSUER)?;
+            self.ensure_not_paused()?;
+
+            let current_block = self.env().block_number() as u64;
+            let mut pruned = 0u32;
+            for session_id in ids {
+                if let Some(grant) = self.sessions.get(session_id) {
+                    if current_block > grant.expires_at_block {
+                        self.sessions.remove(session_id);
+                        self.clear_session_index(&grant, session_id);
+                        self.env().emit_event(SessionExpired { session_id });
+                        pruned += 1;
+                    }
+                }
+            }
+
+            Ok(pruned)
+        }
+
+        /// Register a document so an off-chain key server can gate access to
+        /// its decryption share.
+        ///
+        /// Only the contract owner can register documents.
+        #[ink(message)]
+        pub fn register_document(
+            &mut self,
+            scope_id: [u8; 32],
+            required_level: EntitlementLevel,
+            encrypted_key: ink::prelude::vec::Vec<u8>,
+        ) -> Result<()> {
             if self.env().caller() != self.owner {
                 return Err(Error::NotOwner);
             }
+            self.ensure_not_paused()?;
+
+            self.documents.insert(
+                scope_id,
+                &DocumentEntry {
+                    required_level,
+                    encrypted_key,
+                },
+            );
+
+            self.env().emit_event(DocumentRegistered {
+                scope_id,
+                required_level,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a previously registered document.
+        ///
+        /// Only the contract owner can retire documents.
+        #[ink(message)]
+        pub fn retire_document(&mut self, scope_id: [u8; 32]) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_not_paused()?;
+
+            self.documents.remove(scope_id);
+
+            self.env().emit_event(DocumentRetired { scope_id });
+
+            Ok(())
+        }
+
+        /// Get a registered document by scope identifier.
+        #[ink(message)]
+        pub fn get_document(&self, scope_id: [u8; 32]) -> Option<DocumentEntry> {
+            self.documents.get(scope_id)
+        }
+
+        /// Decide whether `account` may access the document at `scope_id`.
+        ///
+        /// An off-chain key server polls this as a read-only query to decide
+        /// whether to release its decryption share. Access is granted when the
+        /// document exists, the account holds the required entitlement, and it
+        /// owns an active session scoped to `scope_id`.
+        ///
+        /// Only the most recent session issued for a given `(holder, scope_id)`
+        /// is tracked, so the registry assumes a single active session per
+        /// scope; issuing a fresh session supersedes the previous pointer.
+        #[ink(message)]
+        pub fn can_access(&self, account: Address, scope_id: [u8; 32]) -> bool {
+            match self.documents.get(scope_id) {
+                Some(document) => {
+                    self.has_entitlement(account, document.required_level)
+                        && self
+                            .account_sessions
+                            .get((account, scope_id))
+                            .map(|session_id| self.is_session_active(session_id))
+                            .unwrap_or(false)
+                }
+                None => false,
+            }
+        }
+
+        /// Record an access check on-chain, emitting [`AccessChecked`].
+        ///
+        /// Unlike the pure-view [`AccessRegistry::can_access`], this is a
+        /// transaction so the emitted event persists, letting a
+        /// service-contract listener react to the request. Returns the same
+        /// decision `can_access` would.
+        #[ink(message)]
+        pub fn check_access(&mut self, account: Address, scope_id: [u8; 32]) -> bool {
+            let granted = self.can_access(account, scope_id);
+
+            self.env().emit_event(AccessChecked {
+                account,
+                scope_id,
+                granted,
+            });
+
+            granted
+        }
+
+        /// Verify that a caller controls the ephemeral key behind a session.
+        ///
+        /// Recovers the public key from an ECDSA `signature` over
+        /// `message_hash` and compares it byte-for-byte against the session's
+        /// stored `eph_pub_key`. Returns `Ok(false)` when the session is
+        /// revoked or has expired, [`Error::SessionNotFound`] for an unknown
+        /// id, and [`Error::SignatureInvalid`] when recovery fails. A relayer
+        /// can call this to validate a signed agent request on-chain before
+        /// releasing the underlying resource.
+        #[ink(message)]
+        pub fn verify_session(
+            &self,
+            session_id: [u8; 32],
+            message_hash: [u8; 32],
+            signature: [u8; 65],
+        ) -> Result<bool> {
+            let grant = self.sessions.get(session_id).ok_or(Error::SessionNotFound)?;
+
+            if grant.is_revoked || self.env().block_number() as u64 > grant.expires_at_block {
+                return Ok(false);
+            }
+
+            let mut recovered = [0u8; 33];
+            self.env()
+                .ecdsa_recover(&signature, &message_hash, &mut recovered)
+                .map_err(|_| Error::SignatureInvalid)?;
+
+            Ok(recovered.as_slice() == grant.eph_pub_key.as_slice())
+        }
+
+        /// Revoke a session grant.
+        ///
+        /// Only holders of [`SESSION_ISSUER`] can revoke sessions.
+        #[ink(message)]
+        pub fn revoke_session(&mut self, session_id: [u8; 32]) -> Result<()> {
+            self.ensure_role(SESSION_ISSUER)?;
+            self.ensure_not_paused()?;
 
             if let Some(mut grant) = self.sessions.get(session_id) {
                 grant.is_revoked = true;
                 self.sessions.insert(session_id, &grant);
+                self.clear_session_index(&grant, session_id);
 
                 self.env().emit_event(SessionRevoked { session_id });
 
@@ -233,6 +820,15 @@ mod access_registry {
                 Err(Error::SessionNotFound)
             }
         }
+
+        /// Clear the `(holder, scope_id)` index entry if it still points at
+        /// `session_id`, so revoked or pruned sessions leave no dangling
+        /// pointer behind.
+        fn clear_session_index(&mut self, grant: &SessionGrant, session_id: [u8; 32]) {
+            if self.account_sessions.get((grant.holder, grant.scope_id)) == Some(session_id) {
+                self.account_sessions.remove((grant.holder, grant.scope_id));
+            }
+        }
     }
 
     #[cfg(test)]
@@ -252,7 +848,7 @@ mod access_registry {
             let account = Address::from([0x02; 20]);
 
             assert!(contract
-                .grant_entitlement(account, EntitlementLevel::Vip)
+                .grant_entitlement(account, EntitlementLevel::Vip, None)
                 .is_ok());
             assert_eq!(contract.get_entitlement(account), EntitlementLevel::Vip);
         }
@@ -263,7 +859,7 @@ mod access_registry {
             let account = Address::from([0x02; 20]);
 
             contract
-                .grant_entitlement(account, EntitlementLevel::Premium)
+                .grant_entitlement(account, EntitlementLevel::Premium, None)
                 .unwrap();
 
             assert!(contract.has_entitlement(account, EntitlementLevel::Basic));
@@ -277,7 +873,7 @@ mod access_registry {
             let account = Address::from([0x02; 20]);
 
             contract
-                .grant_entitlement(account, EntitlementLevel::Vip)
+                .grant_entitlement(account, EntitlementLevel::Vip, None)
                 .unwrap();
             assert!(contract.revoke_entitlement(account).is_ok());
             assert_eq!(contract.get_entitlement(account), EntitlementLevel::None);
@@ -292,7 +888,7 @@ mod access_registry {
             let expires_at_block = 1000u64;
 
             assert!(contract
-                .create_session(session_id, eph_pub_key.clone(), scope_id, expires_at_block)
+                .create_session(session_id, Address::default(), eph_pub_key.clone(), scope_id, expires_at_block)
                 .is_ok());
 
             let grant = contract.get_session(session_id);
@@ -320,7 +916,7 @@ mod access_registry {
             let expires_at_block = 1000u64;
 
             contract
-                .create_session(session_id, eph_pub_key, scope_id, expires_at_block)
+                .create_session(session_id, Address::default(), eph_pub_key, scope_id, expires_at_block)
                 .unwrap();
 
             assert!(contract.revoke_session(session_id).is_ok());
@@ -338,5 +934,337 @@ mod access_registry {
                 Err(Error::SessionNotFound)
             );
         }
+
+        #[ink::test]
+        fn two_step_ownership_transfer_works() {
+            let mut contract = AccessRegistry::new();
+            let new_owner = Address::from([0x04; 20]);
+
+            assert!(contract.pending_owner().is_none());
+            assert!(contract.propose_owner(new_owner).is_ok());
+            assert_eq!(contract.pending_owner(), Some(new_owner));
+            // Ownership does not move until accepted.
+            assert_eq!(contract.owner(), Address::default());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(new_owner);
+            assert!(contract.accept_ownership().is_ok());
+            assert_eq!(contract.owner(), new_owner);
+            assert!(contract.pending_owner().is_none());
+        }
+
+        #[ink::test]
+        fn accept_ownership_rejects_non_pending() {
+            let mut contract = AccessRegistry::new();
+            let new_owner = Address::from([0x04; 20]);
+            contract.propose_owner(new_owner).unwrap();
+
+            let stranger = Address::from([0x09; 20]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(stranger);
+            assert_eq!(contract.accept_ownership(), Err(Error::NotPendingOwner));
+        }
+
+        #[ink::test]
+        fn renounce_ownership_clears_owner() {
+            let mut contract = AccessRegistry::new();
+            contract.propose_owner(Address::from([0x04; 20])).unwrap();
+            assert!(contract.renounce_ownership().is_ok());
+            assert_eq!(contract.owner(), Address::default());
+            assert!(contract.pending_owner().is_none());
+        }
+
+        #[ink::test]
+        fn entitlement_lapses_after_expiry() {
+            let mut contract = AccessRegistry::new();
+            let account = Address::from([0x02; 20]);
+
+            contract
+                .grant_entitlement(account, EntitlementLevel::Premium, Some(0))
+                .unwrap();
+            assert_eq!(contract.get_entitlement(account), EntitlementLevel::Premium);
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.get_entitlement(account), EntitlementLevel::None);
+            assert!(!contract.has_entitlement(account, EntitlementLevel::Premium));
+        }
+
+        #[ink::test]
+        fn sweep_expired_entitlements_removes_lapsed() {
+            let mut contract = AccessRegistry::new();
+            let expired = Address::from([0x02; 20]);
+            let perpetual = Address::from([0x03; 20]);
+
+            contract
+                .grant_entitlement(expired, EntitlementLevel::Premium, Some(0))
+                .unwrap();
+            contract
+                .grant_entitlement(perpetual, EntitlementLevel::Vip, None)
+                .unwrap();
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+
+            let swept = contract
+                .sweep_expired_entitlements(ink::prelude::vec![expired, perpetual])
+                .unwrap();
+            assert_eq!(swept, 1);
+            assert_eq!(contract.get_entitlement(perpetual), EntitlementLevel::Vip);
+        }
+
+        #[ink::test]
+        fn deployer_gets_bootstrap_roles() {
+            let contract = AccessRegistry::new();
+            let deployer = Address::default();
+            assert!(contract.has_role(DEFAULT_ADMIN_ROLE, deployer));
+            assert!(contract.has_role(ENTITLEMENT_ADMIN, deployer));
+            assert!(contract.has_role(SESSION_ISSUER, deployer));
+        }
+
+        #[ink::test]
+        fn grant_and_revoke_role_work() {
+            let mut contract = AccessRegistry::new();
+            let account = Address::from([0x07; 20]);
+
+            assert!(!contract.has_role(ENTITLEMENT_ADMIN, account));
+            assert!(contract.grant_role(ENTITLEMENT_ADMIN, account).is_ok());
+            assert!(contract.has_role(ENTITLEMENT_ADMIN, account));
+
+            assert!(contract.revoke_role(ENTITLEMENT_ADMIN, account).is_ok());
+            assert!(!contract.has_role(ENTITLEMENT_ADMIN, account));
+        }
+
+        #[ink::test]
+        fn renounce_role_works() {
+            let mut contract = AccessRegistry::new();
+            let deployer = Address::default();
+            assert!(contract.renounce_role(SESSION_ISSUER).is_ok());
+            assert!(!contract.has_role(SESSION_ISSUER, deployer));
+        }
+
+        #[ink::test]
+        fn set_role_admin_reroutes_membership_control() {
+            let mut contract = AccessRegistry::new();
+            assert_eq!(contract.get_role_admin(SESSION_ISSUER), DEFAULT_ADMIN_ROLE);
+            assert!(contract
+                .set_role_admin(SESSION_ISSUER, ENTITLEMENT_ADMIN)
+                .is_ok());
+            assert_eq!(contract.get_role_admin(SESSION_ISSUER), ENTITLEMENT_ADMIN);
+        }
+
+        #[ink::test]
+        fn is_session_active_tracks_revocation() {
+            let mut contract = AccessRegistry::new();
+            let session_id = [0x01u8; 32];
+            contract
+                .create_session(session_id, Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 1000)
+                .unwrap();
+
+            assert!(contract.is_session_active(session_id));
+            contract.revoke_session(session_id).unwrap();
+            assert!(!contract.is_session_active(session_id));
+            assert!(!contract.is_session_active([0x99u8; 32]));
+        }
+
+        #[ink::test]
+        fn prune_expired_sessions_removes_only_lapsed() {
+            let mut contract = AccessRegistry::new();
+            let expired = [0x01u8; 32];
+            let live = [0x02u8; 32];
+            contract
+                .create_session(expired, Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 0)
+                .unwrap();
+            contract
+                .create_session(live, Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 1000)
+                .unwrap();
+
+            // Advance past the expired session's deadline.
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+
+            let pruned = contract
+                .prune_expired_sessions(ink::prelude::vec![expired, live])
+                .unwrap();
+            assert_eq!(pruned, 1);
+            assert!(contract.get_session(expired).is_none());
+            assert!(contract.get_session(live).is_some());
+        }
+
+        #[ink::test]
+        fn register_and_retire_document_work() {
+            let mut contract = AccessRegistry::new();
+            let scope_id = [0x05u8; 32];
+
+            assert!(contract
+                .register_document(scope_id, EntitlementLevel::Premium, ink::prelude::vec![0xAAu8; 16])
+                .is_ok());
+            let document = contract.get_document(scope_id).unwrap();
+            assert_eq!(document.required_level, EntitlementLevel::Premium);
+            assert_eq!(document.encrypted_key, ink::prelude::vec![0xAAu8; 16]);
+
+            assert!(contract.retire_document(scope_id).is_ok());
+            assert!(contract.get_document(scope_id).is_none());
+        }
+
+        #[ink::test]
+        fn can_access_requires_entitlement_and_session() {
+            let mut contract = AccessRegistry::new();
+            let account = Address::from([0x02; 20]);
+            let scope_id = [0x05u8; 32];
+            let session_id = [0x06u8; 32];
+
+            contract
+                .register_document(scope_id, EntitlementLevel::Premium, ink::prelude::vec![0xAAu8; 16])
+                .unwrap();
+
+            // No entitlement, no session yet.
+            assert!(!contract.can_access(account, scope_id));
+
+            contract
+                .grant_entitlement(account, EntitlementLevel::Premium, None)
+                .unwrap();
+            // Entitled but still no session scoped here.
+            assert!(!contract.can_access(account, scope_id));
+
+            contract
+                .create_session(session_id, account, ink::prelude::vec![0x02u8; 33], scope_id, 1000)
+                .unwrap();
+            assert!(contract.can_access(account, scope_id));
+
+            // Unknown document is never accessible.
+            assert!(!contract.can_access(account, [0x99u8; 32]));
+        }
+
+        #[ink::test]
+        fn verify_session_unknown_is_not_found() {
+            let contract = AccessRegistry::new();
+            assert_eq!(
+                contract.verify_session([0x99u8; 32], [0u8; 32], [0u8; 65]),
+                Err(Error::SessionNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn verify_session_false_when_revoked() {
+            let mut contract = AccessRegistry::new();
+            let session_id = [0x01u8; 32];
+            contract
+                .create_session(session_id, Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 1000)
+                .unwrap();
+            contract.revoke_session(session_id).unwrap();
+
+            assert_eq!(
+                contract.verify_session(session_id, [0u8; 32], [0u8; 65]),
+                Ok(false)
+            );
+        }
+
+        #[ink::test]
+        fn verify_session_checks_recovered_key() {
+            // A genuine secp256k1 `(r, s)` pair. The message it was originally
+            // signed over is irrelevant here: we recover the public key for
+            // these exact inputs and store it, then assert `verify_session`
+            // recovers the same key. Trying every recovery id keeps the test
+            // independent of which one this `(r, s)` needs.
+            let rs: [u8; 64] = [
+                161, 234, 203, 74, 147, 96, 51, 212, 5, 174, 231, 9, 92, 45, 202, 68,
+                246, 59, 212, 250, 188, 10, 238, 192, 196, 82, 204, 182, 31, 91, 161,
+                192, 12, 14, 166, 28, 5, 77, 45, 166, 28, 154, 98, 79, 161, 3, 164,
+                42, 74, 117, 199, 157, 31, 196, 142, 95, 35, 110, 170, 52, 34, 150,
+                26, 251,
+            ];
+            let message_hash: [u8; 32] = [
+                162, 28, 244, 179, 96, 76, 244, 178, 188, 83, 230, 248, 143, 106, 77,
+                117, 239, 95, 244, 171, 65, 95, 62, 153, 174, 166, 182, 28, 130, 73,
+                196, 208,
+            ];
+
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&rs);
+            let mut recovered = [0u8; 33];
+            let mut valid_signature = None;
+            for rec_id in 0u8..=3 {
+                signature[64] = rec_id;
+                if ink::env::ecdsa_recover(&signature, &message_hash, &mut recovered).is_ok() {
+                    valid_signature = Some(signature);
+                    break;
+                }
+            }
+            let signature = valid_signature.expect("a recovery id yields a valid key");
+
+            let mut contract = AccessRegistry::new();
+            let matching = [0x01u8; 32];
+            contract
+                .create_session(matching, Address::default(), recovered.to_vec(), [0x03u8; 32], 1000)
+                .unwrap();
+            assert_eq!(
+                contract.verify_session(matching, message_hash, signature),
+                Ok(true)
+            );
+
+            // A session whose stored key differs from the recovered one.
+            let mismatched = [0x07u8; 32];
+            contract
+                .create_session(mismatched, Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 1000)
+                .unwrap();
+            assert_eq!(
+                contract.verify_session(mismatched, message_hash, signature),
+                Ok(false)
+            );
+        }
+
+        #[ink::test]
+        fn verify_session_rejects_malformed_signature() {
+            let mut contract = AccessRegistry::new();
+            let session_id = [0x01u8; 32];
+            contract
+                .create_session(session_id, Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 1000)
+                .unwrap();
+
+            assert_eq!(
+                contract.verify_session(session_id, [0x11u8; 32], [0u8; 65]),
+                Err(Error::SignatureInvalid)
+            );
+        }
+
+        #[ink::test]
+        fn pause_and_unpause_work() {
+            let mut contract = AccessRegistry::new();
+            assert!(!contract.is_paused());
+
+            assert!(contract.pause().is_ok());
+            assert!(contract.is_paused());
+
+            assert!(contract.unpause().is_ok());
+            assert!(!contract.is_paused());
+        }
+
+        #[ink::test]
+        fn paused_blocks_mutations_but_not_reads() {
+            let mut contract = AccessRegistry::new();
+            let account = Address::from([0x02; 20]);
+
+            contract
+                .grant_entitlement(account, EntitlementLevel::Vip, None)
+                .unwrap();
+            contract.pause().unwrap();
+
+            assert_eq!(
+                contract.grant_entitlement(account, EntitlementLevel::Basic, None),
+                Err(Error::ContractPaused)
+            );
+            assert_eq!(
+                contract.revoke_entitlement(account),
+                Err(Error::ContractPaused)
+            );
+            assert_eq!(
+                contract.create_session([0x01u8; 32], Address::default(), ink::prelude::vec![0x02u8; 33], [0x03u8; 32], 1000),
+                Err(Error::ContractPaused)
+            );
+            assert_eq!(
+                contract.revoke_session([0x01u8; 32]),
+                Err(Error::ContractPaused)
+            );
+
+            // Read-only messages stay live while paused.
+            assert_eq!(contract.get_entitlement(account), EntitlementLevel::Vip);
+            assert!(contract.get_session([0x01u8; 32]).is_none());
+        }
     }
 }